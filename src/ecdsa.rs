@@ -1,7 +1,74 @@
+use crate::secret_key::SecretKey;
 use ec_generic::{EllipticCurve, FiniteField, Point};
+use hmac::{Hmac, Mac};
 use num_bigint::{BigUint, RandBigInt};
 use rand;
+use sha2::Sha256;
 use sha256::digest;
+use std::fmt;
+use zeroize::Zeroize;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ECDSAErrors {
+    InvalidPublicKeyEncoding,
+    PublicKeyNotOnCurve,
+    InvalidSignatureEncoding,
+    PointIsIdentity,
+    HashTooLarge,
+    PrivateKeyOutOfRange,
+    RandomNumberOutOfRange,
+    InvalidSignatureRange,
+    InvalidVrfProof,
+}
+
+impl fmt::Display for ECDSAErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ECDSAErrors::InvalidPublicKeyEncoding => {
+                write!(f, "public key encoding is malformed")
+            }
+            ECDSAErrors::PublicKeyNotOnCurve => {
+                write!(f, "point does not lie on the curve")
+            }
+            ECDSAErrors::InvalidSignatureEncoding => {
+                write!(f, "signature encoding is malformed")
+            }
+            ECDSAErrors::PointIsIdentity => {
+                write!(f, "point is the identity element")
+            }
+            ECDSAErrors::HashTooLarge => {
+                write!(f, "hash is bigger than the order of the EC group")
+            }
+            ECDSAErrors::PrivateKeyOutOfRange => {
+                write!(f, "private key is zero or not less than the order of the EC group")
+            }
+            ECDSAErrors::RandomNumberOutOfRange => {
+                write!(
+                    f,
+                    "random number `k` is bigger than the order of the EC group"
+                )
+            }
+            ECDSAErrors::InvalidSignatureRange => {
+                write!(f, "signature component `r` or `s` is zero")
+            }
+            ECDSAErrors::InvalidVrfProof => {
+                write!(f, "VRF proof failed verification")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ECDSAErrors {}
+
+/// Named curves with vetted, standard parameters, so callers don't have to hand-construct
+/// an `EllipticCurve` from hex strings.
+pub enum StandardCurve {
+    Secp256k1,
+    Secp256r1,
+    Secp384r1,
+}
 
 pub struct ECDSA {
     elliptic_curve: EllipticCurve,
@@ -10,19 +77,90 @@ pub struct ECDSA {
 }
 
 impl ECDSA {
+    /// Builds a signer already parameterized for a well-known curve, instead of requiring
+    /// the caller to assemble `a`, `b`, `p`, the generator and the order by hand.
+    pub fn from_standard_curve(curve: StandardCurve) -> ECDSA {
+        let parse_hex = |bytes: &[u8]| {
+            BigUint::parse_bytes(bytes, 16).expect("hardcoded curve constant should parse")
+        };
+
+        let (p, a, b, gx, gy, q_order) = match curve {
+            StandardCurve::Secp256k1 => (
+                parse_hex(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"),
+                BigUint::from(0u32),
+                BigUint::from(7u32),
+                parse_hex(b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"),
+                parse_hex(b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8"),
+                parse_hex(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"),
+            ),
+            StandardCurve::Secp256r1 => (
+                parse_hex(b"FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF"),
+                parse_hex(b"FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC"),
+                parse_hex(b"5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B"),
+                parse_hex(b"6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296"),
+                parse_hex(b"4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5"),
+                parse_hex(b"FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551"),
+            ),
+            StandardCurve::Secp384r1 => (
+                parse_hex(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFFFF0000000000000000FFFFFFFF"),
+                parse_hex(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFFFF0000000000000000FFFFFFFC"),
+                parse_hex(b"B3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875AC656398D8A2ED19D2A85C8EDD3EC2AEF"),
+                parse_hex(b"AA87CA22BE8B05378EB1C71EF320AD746E1D3B628BA79B9859F741E082542A385502F25DBF55296C3A545E3872760AB7"),
+                parse_hex(b"3617DE4A96262C6F5D9E98BF9292DC29F8F41DBD289A147CE9DA3113B5F0B8C00A60B1CE1D7E819D7A431D7C90EA0E5F"),
+                parse_hex(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973"),
+            ),
+        };
+
+        ECDSA {
+            elliptic_curve: EllipticCurve { a, b, p },
+            a_gen: Point::Coor(gx, gy),
+            q_order,
+        }
+    }
+
     // Generates: d, B where B = d A
-    pub fn generate_key_pair(&self) -> (BigUint, Point) {
+    pub fn generate_key_pair(&self) -> (SecretKey, Point) {
         let priv_key = self.generate_priv_key();
         let pub_key = self.generate_pub_key(&priv_key);
         (priv_key, pub_key)
     }
 
-    pub fn generate_priv_key(&self) -> BigUint {
-        self.generate_random_positive_number_less_than(&self.q_order)
+    pub fn generate_priv_key(&self) -> SecretKey {
+        let scalar = self.generate_random_positive_number_less_than(&self.q_order);
+        SecretKey::from_scalar(&scalar, self.priv_key_byte_len())
+    }
+
+    /// Builds a `SecretKey` from existing key material (e.g. imported from a wallet or HD
+    /// derivation), rejecting scalars that are zero or outside `[1, q_order)`.
+    pub fn priv_key_from_scalar(&self, scalar: &BigUint) -> Result<SecretKey, ECDSAErrors> {
+        if *scalar == BigUint::from(0u32) || *scalar >= self.q_order {
+            return Err(ECDSAErrors::PrivateKeyOutOfRange);
+        }
+
+        Ok(SecretKey::from_scalar(scalar, self.priv_key_byte_len()))
+    }
+
+    pub fn generate_pub_key(&self, priv_key: &SecretKey) -> Point {
+        // `BigUint` can't be scrubbed through its public API (see `SecretKey`'s doc comment),
+        // so the exposed scalar is held for the shortest possible scope and nothing more.
+        let scalar = priv_key.expose_scalar();
+        self.elliptic_curve.scalar_mul(&self.a_gen, &scalar)
+    }
+
+    fn priv_key_byte_len(&self) -> usize {
+        self.q_order.bits().div_ceil(8) as usize
+    }
+
+    pub(crate) fn curve(&self) -> &EllipticCurve {
+        &self.elliptic_curve
+    }
+
+    pub(crate) fn generator(&self) -> &Point {
+        &self.a_gen
     }
 
-    pub fn generate_pub_key(&self, priv_key: &BigUint) -> Point {
-        self.elliptic_curve.scalar_mul(&self.a_gen, &priv_key)
+    pub(crate) fn order(&self) -> &BigUint {
+        &self.q_order
     }
 
     // (0, max)
@@ -32,55 +170,263 @@ impl ECDSA {
     }
 
     ///
-    /// R = k A -> take `r = x` component
+    /// R = k A -> take `r = x mod q` component
     /// s = (hash(message) + d * r) * k^(-1) mod q
     ///
     pub fn sign(
         &self,
         hash: &BigUint,
-        priv_key: &BigUint,
+        priv_key: &SecretKey,
         k_random: &BigUint,
-    ) -> (BigUint, BigUint) {
-        assert!(
-            *hash < self.q_order,
-            "Hash is bigger than the order of the EC group"
-        );
-        assert!(
-            *priv_key < self.q_order,
-            "Private key is bigger than the order of the EC group"
-        );
-        assert!(
-            *k_random < self.q_order,
-            "Random number `k` is bigger than the order of the EC group"
-        );
+    ) -> Result<(BigUint, BigUint), ECDSAErrors> {
+        if *hash >= self.q_order {
+            return Err(ECDSAErrors::HashTooLarge);
+        }
+        if *k_random >= self.q_order {
+            return Err(ECDSAErrors::RandomNumberOutOfRange);
+        }
+
+        // `BigUint` can't be scrubbed through its public API (see `SecretKey`'s doc comment),
+        // so the exposed scalar is held for the shortest possible scope and nothing more.
+        let priv_key = priv_key.expose_scalar();
+
+        if priv_key >= self.q_order {
+            return Err(ECDSAErrors::PrivateKeyOutOfRange);
+        }
 
         let r_point = self.elliptic_curve.scalar_mul(&self.a_gen, k_random);
 
-        if let Point::Coor(r, _) = r_point {
-            let s = FiniteField::mult(&r, priv_key, &self.q_order);
-            let s = FiniteField::add(&s, hash, &self.q_order);
-            let k_inv = FiniteField::inv_mult_prime(k_random, &self.q_order);
-            let s = FiniteField::mult(&s, &k_inv, &self.q_order);
+        let r = match r_point {
+            Point::Coor(x, _) => x % &self.q_order,
+            Point::Identity => return Err(ECDSAErrors::PointIsIdentity),
+        };
+
+        let s = FiniteField::mult(&r, &priv_key, &self.q_order);
+        let s = FiniteField::add(&s, hash, &self.q_order);
+        let k_inv = FiniteField::inv_mult_prime(k_random, &self.q_order);
+        let s = FiniteField::mult(&s, &k_inv, &self.q_order);
 
-            return (r, s);
+        if r == BigUint::from(0u32) || s == BigUint::from(0u32) {
+            return Err(ECDSAErrors::InvalidSignatureRange);
         }
 
-        panic!("The random point R should not be the identity");
+        Ok((r, s))
+    }
+
+    ///
+    /// Signs like `sign`, but derives `k` deterministically from the private key and the
+    /// message hash following RFC 6979, instead of requiring the caller to supply it.
+    ///
+    /// This avoids the classic footgun where a reused or biased `k` leaks the private key,
+    /// while still producing a signature that is reproducible given the same inputs.
+    ///
+    pub fn sign_deterministic(
+        &self,
+        hash: &BigUint,
+        priv_key: &SecretKey,
+    ) -> Result<(BigUint, BigUint), ECDSAErrors> {
+        let priv_key_scalar = priv_key.expose_scalar();
+        let k = self.generate_deterministic_k(hash, &priv_key_scalar);
+        self.sign(hash, priv_key, &k)
+    }
+
+    ///
+    /// Signs like `sign`, but also returns a recovery id `v` (0..=3) that lets
+    /// `recover_pub_key` reconstruct the signer's public key from `(hash, signature, v)`
+    /// alone, without the public key being transmitted.
+    ///
+    /// `v`'s bit 0 is the parity of `R.y`; bit 1 is set when `R.x` overflowed `q_order`
+    /// and had to be reduced to produce `r`.
+    ///
+    pub fn sign_with_recovery(
+        &self,
+        hash: &BigUint,
+        priv_key: &SecretKey,
+        k_random: &BigUint,
+    ) -> Result<(BigUint, BigUint, u8), ECDSAErrors> {
+        let signature = self.sign(hash, priv_key, k_random)?;
+
+        let r_point = self.elliptic_curve.scalar_mul(&self.a_gen, k_random);
+        let (x, y) = match r_point {
+            Point::Coor(x, y) => (x, y),
+            Point::Identity => return Err(ECDSAErrors::PointIsIdentity),
+        };
+
+        let mut v = if y.bit(0) { 0x01 } else { 0x00 };
+        if x >= self.q_order {
+            v |= 0x02;
+        }
+
+        Ok((signature.0, signature.1, v))
+    }
+
+    /// Deterministic (RFC 6979) counterpart to `sign_with_recovery`.
+    pub fn sign_deterministic_with_recovery(
+        &self,
+        hash: &BigUint,
+        priv_key: &SecretKey,
+    ) -> Result<(BigUint, BigUint, u8), ECDSAErrors> {
+        let priv_key_scalar = priv_key.expose_scalar();
+        let k = self.generate_deterministic_k(hash, &priv_key_scalar);
+        self.sign_with_recovery(hash, priv_key, &k)
+    }
+
+    ///
+    /// Recovers the signer's public key from a signature and its recovery id `v`.
+    ///
+    /// `R` is reconstructed from `r` (adding `q_order` to `x` when `v` indicates the
+    /// original `R.x` overflowed `q_order`) and decompressed using the parity bit in `v`.
+    /// The public key is then `Q = r^(-1) * (s*R - hash*A) mod q_order`.
+    ///
+    pub fn recover_pub_key(
+        &self,
+        hash: &BigUint,
+        sig: &(BigUint, BigUint),
+        v: u8,
+    ) -> Result<Point, ECDSAErrors> {
+        let (r, s) = sig;
+
+        if *r == BigUint::from(0u32) || *s == BigUint::from(0u32) {
+            return Err(ECDSAErrors::InvalidSignatureRange);
+        }
+
+        let mut x = r.clone();
+        if v & 0x02 != 0 {
+            x += &self.q_order;
+        }
+        let y = self.decompress_y(&x, v & 0x01 != 0)?;
+        let r_point = self.checked_point(x, y)?;
+
+        let neg_hash = if *hash == BigUint::from(0u32) {
+            BigUint::from(0u32)
+        } else {
+            &self.q_order - hash
+        };
+
+        let s_r = self.elliptic_curve.scalar_mul(&r_point, s);
+        let neg_hash_a = self.elliptic_curve.scalar_mul(&self.a_gen, &neg_hash);
+        let sum = self.elliptic_curve.add(&s_r, &neg_hash_a);
+
+        let r_inv = FiniteField::inv_mult_prime(r, &self.q_order);
+        let pub_key = self.elliptic_curve.scalar_mul(&sum, &r_inv);
+
+        match pub_key {
+            Point::Coor(_, _) => Ok(pub_key),
+            Point::Identity => Err(ECDSAErrors::PointIsIdentity),
+        }
+    }
+
+    /// RFC 6979 section 3.2: deterministically derive `k` from `priv_key` and `hash` via
+    /// HMAC-SHA256, retrying until a candidate in `[1, q_order)` is found.
+    pub(crate) fn generate_deterministic_k(&self, hash: &BigUint, priv_key: &BigUint) -> BigUint {
+        let qlen = self.q_order.bits();
+        let mut int2octets_x = self.int2octets(priv_key);
+        let bits2octets_h = self.bits2octets(hash);
+
+        let mut v = [0x01u8; 32];
+        let mut k = [0x00u8; 32];
+
+        let mut data = Vec::with_capacity(v.len() + 1 + int2octets_x.len() + bits2octets_h.len());
+        data.extend_from_slice(&v);
+        data.push(0x00);
+        data.extend_from_slice(&int2octets_x);
+        data.extend_from_slice(&bits2octets_h);
+        k = Self::hmac_sha256(&k, &data);
+        v = Self::hmac_sha256(&k, &v);
+
+        data.clear();
+        data.extend_from_slice(&v);
+        data.push(0x01);
+        data.extend_from_slice(&int2octets_x);
+        data.extend_from_slice(&bits2octets_h);
+        k = Self::hmac_sha256(&k, &data);
+        v = Self::hmac_sha256(&k, &v);
+
+        // `int2octets_x` and `data` hold copies of the raw private-key bytes and are done
+        // being used at this point; scrub them rather than leaving them for a normal drop.
+        int2octets_x.zeroize();
+        data.zeroize();
+
+        loop {
+            let mut t: Vec<u8> = Vec::new();
+            while (t.len() as u64) * 8 < qlen {
+                v = Self::hmac_sha256(&k, &v);
+                t.extend_from_slice(&v);
+            }
+
+            let candidate = Self::bits2int(&t, qlen);
+            if candidate >= BigUint::from(1u32) && candidate < self.q_order {
+                return candidate;
+            }
+
+            let mut retry_data = Vec::with_capacity(v.len() + 1);
+            retry_data.extend_from_slice(&v);
+            retry_data.push(0x00);
+            k = Self::hmac_sha256(&k, &retry_data);
+            v = Self::hmac_sha256(&k, &v);
+        }
+    }
+
+    fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+        let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts a key of any length");
+        mac.update(data);
+        mac.finalize().into_bytes().into()
+    }
+
+    /// RFC 6979 `int2octets`: `x` zero-padded on the left to `ceil(qlen/8)` bytes, big-endian.
+    fn int2octets(&self, x: &BigUint) -> Vec<u8> {
+        Self::pad_to(&x.to_bytes_be(), self.priv_key_byte_len())
+    }
+
+    /// RFC 6979 `bits2octets`: reduce `h` mod `q_order`, then encode like `int2octets`.
+    fn bits2octets(&self, hash: &BigUint) -> Vec<u8> {
+        let z = hash % &self.q_order;
+        self.int2octets(&z)
+    }
+
+    /// RFC 6979 `bits2int`: interpret `t` as a big-endian integer, keeping only the
+    /// leftmost `qlen` bits.
+    fn bits2int(t: &[u8], qlen: u64) -> BigUint {
+        let x = BigUint::from_bytes_be(t);
+        let tlen = (t.len() as u64) * 8;
+        if tlen > qlen {
+            x >> (tlen - qlen)
+        } else {
+            x
+        }
     }
 
     ///
     /// u1 = s^(-1) * hash(message) mod q
     /// u2 = s^(-1) * r mod q
     /// P = u1 A + u2 B mod q = (xp, yp)
-    /// if r == xp then verified!
+    /// if r == xp mod q then verified!
     ///
-    pub fn verify(&self, hash: &BigUint, pub_key: &Point, signature: &(BigUint, BigUint)) -> bool {
-        assert!(
-            *hash < self.q_order,
-            "Hash is bigger than the order of the EC group"
-        );
+    /// `require_low_s` rejects signatures whose `s` is not already the low-S normalized
+    /// value, guarding against signature malleability.
+    ///
+    pub fn verify(
+        &self,
+        hash: &BigUint,
+        pub_key: &Point,
+        signature: &(BigUint, BigUint),
+        require_low_s: bool,
+    ) -> Result<bool, ECDSAErrors> {
+        if *hash >= self.q_order {
+            return Err(ECDSAErrors::HashTooLarge);
+        }
+
+        self.validate_pub_key(pub_key)?;
 
         let (r, s) = signature;
+        if *r == BigUint::from(0u32) || *s == BigUint::from(0u32) {
+            return Err(ECDSAErrors::InvalidSignatureRange);
+        }
+
+        if require_low_s && *s != self.normalize_s(s) {
+            return Ok(false);
+        }
+
         let s_inv = FiniteField::inv_mult_prime(&s, &self.q_order);
         let u1 = FiniteField::mult(&s_inv, hash, &self.q_order);
         let u2 = FiniteField::mult(&s_inv, &r, &self.q_order);
@@ -88,11 +434,10 @@ impl ECDSA {
         let u2b = self.elliptic_curve.scalar_mul(&pub_key, &u2);
         let p = self.elliptic_curve.add(&u1a, &u2b);
 
-        if let Point::Coor(xp, _) = p {
-            return xp == *r;
+        match p {
+            Point::Coor(xp, _) => Ok(xp % &self.q_order == *r),
+            Point::Identity => Err(ECDSAErrors::PointIsIdentity),
         }
-
-        panic!("Point P = u1 A + u2 B cannot be the identity");
     }
 
     /// 0 < hash < max
@@ -104,6 +449,230 @@ impl ECDSA {
         let hash = hash + BigUint::from(1u32);
         hash
     }
+
+    /// Width, in bytes, of a field element for this curve's prime `p`.
+    fn field_byte_len(&self) -> usize {
+        ((self.elliptic_curve.p.bits() + 7) / 8) as usize
+    }
+
+    /// SEC1 compressed point encoding: `0x02`/`0x03` (even/odd `y`) followed by `x` as a
+    /// fixed-width big-endian integer.
+    pub fn serialize_pub_key_compressed(&self, p: &Point) -> Result<Vec<u8>, ECDSAErrors> {
+        let (x, y) = match p {
+            Point::Coor(x, y) => (x, y),
+            Point::Identity => return Err(ECDSAErrors::PointIsIdentity),
+        };
+
+        let prefix = if y.bit(0) { 0x03 } else { 0x02 };
+
+        let mut encoded = Vec::with_capacity(1 + self.field_byte_len());
+        encoded.push(prefix);
+        encoded.extend(Self::pad_to(&x.to_bytes_be(), self.field_byte_len()));
+        Ok(encoded)
+    }
+
+    /// SEC1 uncompressed point encoding: `0x04 || x || y`.
+    pub fn serialize_pub_key_uncompressed(&self, p: &Point) -> Result<Vec<u8>, ECDSAErrors> {
+        let (x, y) = match p {
+            Point::Coor(x, y) => (x, y),
+            Point::Identity => return Err(ECDSAErrors::PointIsIdentity),
+        };
+
+        let field_len = self.field_byte_len();
+        let mut encoded = Vec::with_capacity(1 + 2 * field_len);
+        encoded.push(0x04);
+        encoded.extend(Self::pad_to(&x.to_bytes_be(), field_len));
+        encoded.extend(Self::pad_to(&y.to_bytes_be(), field_len));
+        Ok(encoded)
+    }
+
+    /// Parses a SEC1-encoded public key, in either compressed or uncompressed form.
+    ///
+    /// Compressed points are decompressed via `y = alpha^((p+1)/4) mod p`, which only
+    /// recovers the square root directly for primes with `p ≡ 3 mod 4` (true of
+    /// secp256k1, secp256r1 and secp384r1); any other prime is rejected.
+    pub fn parse_pub_key(&self, bytes: &[u8]) -> Result<Point, ECDSAErrors> {
+        let field_len = self.field_byte_len();
+
+        match bytes.first() {
+            Some(0x04) if bytes.len() == 1 + 2 * field_len => {
+                let x = BigUint::from_bytes_be(&bytes[1..1 + field_len]);
+                let y = BigUint::from_bytes_be(&bytes[1 + field_len..]);
+                self.checked_point(x, y)
+            }
+            Some(prefix @ (0x02 | 0x03)) if bytes.len() == 1 + field_len => {
+                let x = BigUint::from_bytes_be(&bytes[1..]);
+                let y = self.decompress_y(&x, *prefix == 0x03)?;
+                self.checked_point(x, y)
+            }
+            _ => Err(ECDSAErrors::InvalidPublicKeyEncoding),
+        }
+    }
+
+    pub(crate) fn decompress_y(&self, x: &BigUint, want_odd: bool) -> Result<BigUint, ECDSAErrors> {
+        let p = &self.elliptic_curve.p;
+
+        if p.modpow(&BigUint::from(1u32), &BigUint::from(4u32)) != BigUint::from(3u32) {
+            return Err(ECDSAErrors::InvalidPublicKeyEncoding);
+        }
+
+        let alpha = FiniteField::add(
+            &FiniteField::mult(&FiniteField::mult(x, x, p), x, p),
+            &FiniteField::add(
+                &FiniteField::mult(&self.elliptic_curve.a, x, p),
+                &self.elliptic_curve.b,
+                p,
+            ),
+            p,
+        );
+
+        let exponent = (p + BigUint::from(1u32)) / BigUint::from(4u32);
+        let y = alpha.modpow(&exponent, p);
+
+        if FiniteField::mult(&y, &y, p) != alpha {
+            return Err(ECDSAErrors::PublicKeyNotOnCurve);
+        }
+
+        if y.bit(0) == want_odd {
+            Ok(y)
+        } else {
+            Ok(p - y)
+        }
+    }
+
+    pub(crate) fn checked_point(&self, x: BigUint, y: BigUint) -> Result<Point, ECDSAErrors> {
+        let point = Point::Coor(x, y);
+        self.validate_pub_key(&point)?;
+        Ok(point)
+    }
+
+    /// Rejects the identity point and any point that does not lie on `elliptic_curve`,
+    /// since accepting either enables invalid-curve attacks against `verify`.
+    pub(crate) fn validate_pub_key(&self, pub_key: &Point) -> Result<(), ECDSAErrors> {
+        let (x, y) = match pub_key {
+            Point::Coor(x, y) => (x, y),
+            Point::Identity => return Err(ECDSAErrors::PointIsIdentity),
+        };
+
+        let p = &self.elliptic_curve.p;
+        let lhs = FiniteField::mult(y, y, p);
+        let rhs = FiniteField::add(
+            &FiniteField::mult(&FiniteField::mult(x, x, p), x, p),
+            &FiniteField::add(
+                &FiniteField::mult(&self.elliptic_curve.a, x, p),
+                &self.elliptic_curve.b,
+                p,
+            ),
+            p,
+        );
+
+        if lhs != rhs {
+            return Err(ECDSAErrors::PublicKeyNotOnCurve);
+        }
+
+        Ok(())
+    }
+
+    fn pad_to(bytes: &[u8], len: usize) -> Vec<u8> {
+        if bytes.len() >= len {
+            return bytes.to_vec();
+        }
+        let mut padded = vec![0u8; len - bytes.len()];
+        padded.extend_from_slice(bytes);
+        padded
+    }
+
+    /// Low-S normalization: if `s > q_order/2`, replace it with `q_order - s`. Prevents
+    /// signature malleability, since `(r, s)` and `(r, q_order - s)` both verify.
+    pub fn normalize_s(&self, s: &BigUint) -> BigUint {
+        let half_q = &self.q_order / BigUint::from(2u32);
+        if *s > half_q {
+            &self.q_order - s
+        } else {
+            s.clone()
+        }
+    }
+
+    /// ASN.1 DER encoding: `SEQUENCE { INTEGER r, INTEGER s }`, with minimal-length
+    /// integers and a leading `0x00` prepended whenever the high bit would otherwise be set.
+    pub fn encode_der(&self, sig: &(BigUint, BigUint)) -> Vec<u8> {
+        let (r, s) = sig;
+        let r_enc = Self::encode_der_integer(r);
+        let s_enc = Self::encode_der_integer(s);
+
+        let mut body = Vec::with_capacity(r_enc.len() + s_enc.len());
+        body.extend(r_enc);
+        body.extend(s_enc);
+
+        let mut der = Vec::with_capacity(2 + body.len());
+        der.push(0x30);
+        der.push(body.len() as u8);
+        der.extend(body);
+        der
+    }
+
+    /// Parses the DER form produced by `encode_der`.
+    pub fn decode_der(&self, bytes: &[u8]) -> Result<(BigUint, BigUint), ECDSAErrors> {
+        if bytes.len() < 2 || bytes[0] != 0x30 || bytes[1] as usize != bytes.len() - 2 {
+            return Err(ECDSAErrors::InvalidSignatureEncoding);
+        }
+
+        let body = &bytes[2..];
+        let (r, consumed) = Self::decode_der_integer(body)?;
+        let (s, _) = Self::decode_der_integer(&body[consumed..])?;
+        Ok((r, s))
+    }
+
+    fn encode_der_integer(x: &BigUint) -> Vec<u8> {
+        let mut bytes = x.to_bytes_be();
+        if bytes.is_empty() {
+            bytes.push(0x00);
+        }
+        if bytes[0] & 0x80 != 0 {
+            bytes.insert(0, 0x00);
+        }
+
+        let mut encoded = Vec::with_capacity(2 + bytes.len());
+        encoded.push(0x02);
+        encoded.push(bytes.len() as u8);
+        encoded.extend(bytes);
+        encoded
+    }
+
+    fn decode_der_integer(bytes: &[u8]) -> Result<(BigUint, usize), ECDSAErrors> {
+        if bytes.len() < 2 || bytes[0] != 0x02 {
+            return Err(ECDSAErrors::InvalidSignatureEncoding);
+        }
+
+        let len = bytes[1] as usize;
+        if bytes.len() < 2 + len {
+            return Err(ECDSAErrors::InvalidSignatureEncoding);
+        }
+
+        let value = BigUint::from_bytes_be(&bytes[2..2 + len]);
+        Ok((value, 2 + len))
+    }
+
+    /// Fixed-width compact encoding: `r || s`, each zero-padded to `ceil(qlen/8)` bytes
+    /// (64 bytes total for the 256-bit curves this crate targets).
+    pub fn encode_compact(&self, sig: &(BigUint, BigUint)) -> Vec<u8> {
+        let (r, s) = sig;
+        let mut encoded = self.int2octets(r);
+        encoded.extend(self.int2octets(s));
+        encoded
+    }
+
+    /// Parses the compact form produced by `encode_compact`.
+    pub fn decode_compact(&self, bytes: &[u8]) -> Result<(BigUint, BigUint), ECDSAErrors> {
+        let rlen = ((self.q_order.bits() + 7) / 8) as usize;
+        if bytes.len() != 2 * rlen {
+            return Err(ECDSAErrors::InvalidSignatureEncoding);
+        }
+
+        let r = BigUint::from_bytes_be(&bytes[..rlen]);
+        let s = BigUint::from_bytes_be(&bytes[rlen..]);
+        Ok((r, s))
+    }
 }
 
 #[cfg(test)]
@@ -128,7 +697,7 @@ mod test {
             q_order,
         };
 
-        let priv_key = BigUint::from(7u32);
+        let priv_key = SecretKey::from_scalar(&BigUint::from(7u32), 1);
         let pub_key = ecdsa.generate_pub_key(&priv_key);
 
         let k_random = BigUint::from(18u32);
@@ -136,9 +705,13 @@ mod test {
         let message = "Bob -> 1 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let signature = ecdsa.sign(&hash, &priv_key, &k_random);
+        let signature = ecdsa
+            .sign(&hash, &priv_key, &k_random)
+            .expect("signing should succeed");
 
-        let verify_result = ecdsa.verify(&hash, &pub_key, &signature);
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
 
         assert!(verify_result, "Verification should success");
     }
@@ -161,7 +734,7 @@ mod test {
             q_order,
         };
 
-        let priv_key = BigUint::from(7u32);
+        let priv_key = SecretKey::from_scalar(&BigUint::from(7u32), 1);
         let pub_key = ecdsa.generate_pub_key(&priv_key);
 
         let k_random = BigUint::from(18u32);
@@ -169,12 +742,16 @@ mod test {
         let message = "Bob -> 1 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let signature = ecdsa.sign(&hash, &priv_key, &k_random);
+        let signature = ecdsa
+            .sign(&hash, &priv_key, &k_random)
+            .expect("signing should succeed");
 
         let message = "Bob -> 2 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let verify_result = ecdsa.verify(&hash, &pub_key, &signature);
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
 
         assert!(
             !verify_result,
@@ -200,7 +777,7 @@ mod test {
             q_order,
         };
 
-        let priv_key = BigUint::from(7u32);
+        let priv_key = SecretKey::from_scalar(&BigUint::from(7u32), 1);
         let pub_key = ecdsa.generate_pub_key(&priv_key);
 
         let k_random = BigUint::from(13u32);
@@ -208,14 +785,18 @@ mod test {
         let message = "Bob -> 1 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let signature = ecdsa.sign(&hash, &priv_key, &k_random);
+        let signature = ecdsa
+            .sign(&hash, &priv_key, &k_random)
+            .expect("signing should succeed");
         let (r, s) = signature;
         let tempered_signature = (
             (r + BigUint::from(1u32)).modpow(&BigUint::from(1u32), &ecdsa.q_order),
             s,
         );
 
-        let verify_result = ecdsa.verify(&hash, &pub_key, &tempered_signature);
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &tempered_signature, false)
+            .expect("verify should not error");
 
         assert!(
             !verify_result,
@@ -263,11 +844,14 @@ mod test {
             q_order,
         };
 
-        let priv_key = BigUint::parse_bytes(
-            b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
-            16,
-        )
-        .expect("Could not convert hex to private key");
+        let priv_key = SecretKey::from_scalar(
+            &BigUint::parse_bytes(
+                b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
+                16,
+            )
+            .expect("Could not convert hex to private key"),
+            32,
+        );
 
         let pub_key = ecdsa.generate_pub_key(&priv_key);
 
@@ -280,9 +864,13 @@ mod test {
         let message = "Bob -> 1 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let signature = ecdsa.sign(&hash, &priv_key, &k_random);
+        let signature = ecdsa
+            .sign(&hash, &priv_key, &k_random)
+            .expect("signing should succeed");
 
-        let verify_result = ecdsa.verify(&hash, &pub_key, &signature);
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
 
         assert!(verify_result, "Verification should have succeed");
     }
@@ -327,11 +915,14 @@ mod test {
             q_order,
         };
 
-        let priv_key = BigUint::parse_bytes(
-            b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
-            16,
-        )
-        .expect("Could not convert hex to private key");
+        let priv_key = SecretKey::from_scalar(
+            &BigUint::parse_bytes(
+                b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
+                16,
+            )
+            .expect("Could not convert hex to private key"),
+            32,
+        );
 
         let pub_key = ecdsa.generate_pub_key(&priv_key);
 
@@ -344,12 +935,16 @@ mod test {
         let message = "Bob -> 1 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let signature = ecdsa.sign(&hash, &priv_key, &k_random);
+        let signature = ecdsa
+            .sign(&hash, &priv_key, &k_random)
+            .expect("signing should succeed");
 
         let message = "Bob -> 2 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let verify_result = ecdsa.verify(&hash, &pub_key, &signature);
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
 
         assert!(
             !verify_result,
@@ -397,11 +992,14 @@ mod test {
             q_order,
         };
 
-        let priv_key = BigUint::parse_bytes(
-            b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
-            16,
-        )
-        .expect("Could not convert hex to private key");
+        let priv_key = SecretKey::from_scalar(
+            &BigUint::parse_bytes(
+                b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
+                16,
+            )
+            .expect("Could not convert hex to private key"),
+            32,
+        );
 
         let pub_key = ecdsa.generate_pub_key(&priv_key);
 
@@ -414,18 +1012,504 @@ mod test {
         let message = "Bob -> 1 BTC -> Alice";
         let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
 
-        let signature = ecdsa.sign(&hash, &priv_key, &k_random);
+        let signature = ecdsa
+            .sign(&hash, &priv_key, &k_random)
+            .expect("signing should succeed");
         let (r, s) = signature;
         let tempered_signature = (
             (r + BigUint::from(1u32)).modpow(&BigUint::from(1u32), &ecdsa.q_order),
             s,
         );
 
-        let verify_result = ecdsa.verify(&hash, &pub_key, &tempered_signature);
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &tempered_signature, false)
+            .expect("verify should not error");
 
         assert!(
             !verify_result,
             "Verification should have failed due to tempered signature"
         );
     }
+
+    #[test]
+    fn test_sign_deterministic_is_reproducible_and_verifies() {
+        let elliptic_curve = EllipticCurve {
+            a: BigUint::from(2u32),
+            b: BigUint::from(2u32),
+            p: BigUint::from(17u32),
+        };
+
+        let a_gen = Point::Coor(BigUint::from(5u32), BigUint::from(1u32));
+
+        let q_order = BigUint::from(19u32);
+
+        let ecdsa = ECDSA {
+            elliptic_curve,
+            a_gen,
+            q_order,
+        };
+
+        let priv_key = SecretKey::from_scalar(&BigUint::from(7u32), 1);
+        let pub_key = ecdsa.generate_pub_key(&priv_key);
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+
+        let signature_1 = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+        let signature_2 = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        assert_eq!(
+            signature_1, signature_2,
+            "Same hash and private key should produce the same signature"
+        );
+
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature_1, false)
+            .expect("verify should not error");
+        assert!(verify_result, "Verification should success");
+    }
+
+    #[test]
+    fn test_secp256_sign_deterministic_verify() {
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .expect("could not convert p");
+
+        let q_order = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16,
+        )
+        .expect("could not convert n");
+
+        let gx = BigUint::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16,
+        )
+        .expect("could not convert gx");
+
+        let gy = BigUint::parse_bytes(
+            b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16,
+        )
+        .expect("could not convert gy");
+
+        let elliptic_curve = EllipticCurve {
+            a: BigUint::from(0u32),
+            b: BigUint::from(7u32),
+            p,
+        };
+
+        let a_gen = Point::Coor(gx, gy);
+
+        let ecdsa = ECDSA {
+            elliptic_curve,
+            a_gen,
+            q_order,
+        };
+
+        let priv_key = SecretKey::from_scalar(
+            &BigUint::parse_bytes(
+                b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
+                16,
+            )
+            .expect("Could not convert hex to private key"),
+            32,
+        );
+
+        let pub_key = ecdsa.generate_pub_key(&priv_key);
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
+
+        assert!(verify_result, "Verification should have succeed");
+    }
+
+    #[test]
+    fn test_from_standard_curve_secp256k1_sign_verify() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
+
+        assert!(verify_result, "Verification should success");
+    }
+
+    #[test]
+    fn test_from_standard_curve_secp256r1_sign_verify() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256r1);
+
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
+
+        assert!(
+            verify_result,
+            "Verification should success on the a != 0 Weierstrass path"
+        );
+    }
+
+    #[test]
+    fn test_from_standard_curve_secp384r1_sign_verify() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp384r1);
+
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
+
+        assert!(
+            verify_result,
+            "Verification should success on the a != 0 Weierstrass path"
+        );
+    }
+
+    /// Verifies `StandardCurve::Secp384r1` against an independently computed P-384 sign
+    /// vector, not just a self-consistent sign/verify round-trip. A prior bug truncated the
+    /// hardcoded `p`, `a` and `q_order` constants; this test recomputes `r`, `s` and the
+    /// public key from known `priv_key`/`k_random`/`hash` inputs against the real curve and
+    /// checks they match exactly, and that `q_order * generator` is the identity (which a
+    /// truncated order would fail).
+    #[test]
+    fn test_secp384r1_known_vector() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp384r1);
+
+        let identity = ecdsa
+            .curve()
+            .scalar_mul(ecdsa.generator(), ecdsa.order());
+        assert_eq!(
+            identity,
+            Point::Identity,
+            "q_order * generator should be the curve's identity element"
+        );
+
+        let priv_key = SecretKey::from_scalar(
+            &BigUint::parse_bytes(
+                b"65FA8B101B0CC152F6F6A674E2E606DE76B1CFC92EC7B11CDFE87AC1B5DDC8B65C3E78ABE3B7C51A7F1E5D7F5F7B1AE",
+                16,
+            )
+            .expect("could not convert priv_key"),
+            48,
+        );
+
+        let k_random = BigUint::parse_bytes(
+            b"1A1B2C3D4E5F60718293A4B5C6D7E8F90112233445566778899AABBCCDDEEFF00112233445566778899AABBCCDDEE",
+            16,
+        )
+        .expect("could not convert k_random");
+
+        let hash = BigUint::parse_bytes(
+            b"DEADBEEF1234567890ABCDEF1234567890ABCDEF1234567890ABCDEF1234",
+            16,
+        )
+        .expect("could not convert hash");
+
+        let expected_pub_x = BigUint::parse_bytes(
+            b"F156339CD247A9C64395D5EB9FA46027341D74A70BA3C891F8D5CA9E250E3B0E05DF8B70AC2D9892696AEE0BF2E12A3",
+            16,
+        )
+        .expect("could not convert expected pub_key x");
+        let expected_pub_y = BigUint::parse_bytes(
+            b"AC92D49E3DBB40EEBCE7EE57FF5DFD670D465AA8B7F34CFB27216BF724FE2649EEB1C5812DAED168E6331C09521804D3",
+            16,
+        )
+        .expect("could not convert expected pub_key y");
+
+        let pub_key = ecdsa.generate_pub_key(&priv_key);
+        assert_eq!(pub_key, Point::Coor(expected_pub_x, expected_pub_y));
+
+        let expected_r = BigUint::parse_bytes(
+            b"5E123F79D7B1DA0672D2B81881CA5BE8FC6E32461E3C9481EF735263C8A36140D01B0B011E322ACD60D5CA1127F89BC0",
+            16,
+        )
+        .expect("could not convert expected r");
+        let expected_s = BigUint::parse_bytes(
+            b"CCD2839BDEC1385F2DE23B18D2E83EA878F8527DC77405BE434A68097FECF121B78B77A3FFAD6BF1453AEFD26AAE10AD",
+            16,
+        )
+        .expect("could not convert expected s");
+
+        let (r, s) = ecdsa
+            .sign(&hash, &priv_key, &k_random)
+            .expect("signing should succeed");
+
+        assert_eq!(r, expected_r);
+        assert_eq!(s, expected_s);
+
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &(r, s), false)
+            .expect("verify should not error");
+        assert!(verify_result, "Verification should succeed");
+    }
+
+    #[test]
+    fn test_compressed_pub_key_roundtrip() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (_priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let compressed = ecdsa
+            .serialize_pub_key_compressed(&pub_key)
+            .expect("pub_key is not the identity");
+        assert_eq!(compressed.len(), 33);
+
+        let parsed = ecdsa.parse_pub_key(&compressed).expect("should parse");
+        assert_eq!(parsed, pub_key);
+    }
+
+    #[test]
+    fn test_uncompressed_pub_key_roundtrip() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (_priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let uncompressed = ecdsa
+            .serialize_pub_key_uncompressed(&pub_key)
+            .expect("pub_key is not the identity");
+        assert_eq!(uncompressed.len(), 65);
+
+        let parsed = ecdsa.parse_pub_key(&uncompressed).expect("should parse");
+        assert_eq!(parsed, pub_key);
+    }
+
+    #[test]
+    fn test_serialize_pub_key_rejects_identity() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+
+        assert_eq!(
+            ecdsa.serialize_pub_key_compressed(&Point::Identity),
+            Err(ECDSAErrors::PointIsIdentity)
+        );
+        assert_eq!(
+            ecdsa.serialize_pub_key_uncompressed(&Point::Identity),
+            Err(ECDSAErrors::PointIsIdentity)
+        );
+    }
+
+    #[test]
+    fn test_parse_pub_key_rejects_malformed_encoding() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+
+        let result = ecdsa.parse_pub_key(&[0x05; 33]);
+
+        assert_eq!(result, Err(ECDSAErrors::InvalidPublicKeyEncoding));
+    }
+
+    #[test]
+    fn test_der_signature_roundtrip() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let der = ecdsa.encode_der(&signature);
+        let decoded = ecdsa.decode_der(&der).expect("should decode");
+
+        assert_eq!(decoded, signature);
+    }
+
+    #[test]
+    fn test_compact_signature_roundtrip() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let compact = ecdsa.encode_compact(&signature);
+        assert_eq!(compact.len(), 64);
+
+        let decoded = ecdsa.decode_compact(&compact).expect("should decode");
+        assert_eq!(decoded, signature);
+    }
+
+    #[test]
+    fn test_verify_rejects_high_s_when_low_s_required() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+        let (r, s) = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let high_s = &ecdsa.q_order - ecdsa.normalize_s(&s);
+        let high_s_signature = (r, high_s);
+
+        assert!(ecdsa
+            .verify(&hash, &pub_key, &high_s_signature, false)
+            .unwrap());
+        assert!(!ecdsa
+            .verify(&hash, &pub_key, &high_s_signature, true)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_recover_pub_key_from_signature() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+
+        let (r, s, v) = ecdsa
+            .sign_deterministic_with_recovery(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let recovered = ecdsa
+            .recover_pub_key(&hash, &(r, s), v)
+            .expect("recovery should succeed");
+
+        assert_eq!(recovered, pub_key);
+    }
+
+    #[test]
+    fn test_recover_pub_key_rejects_zero_r_or_s() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+
+        let (_r, s, v) = ecdsa
+            .sign_deterministic_with_recovery(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let result = ecdsa.recover_pub_key(&hash, &(BigUint::from(0u32), s.clone()), v);
+        assert_eq!(result, Err(ECDSAErrors::InvalidSignatureRange));
+
+        let result = ecdsa.recover_pub_key(&hash, &(BigUint::from(1u32), BigUint::from(0u32)), v);
+        assert_eq!(result, Err(ECDSAErrors::InvalidSignatureRange));
+    }
+
+    #[test]
+    fn test_priv_key_from_scalar_roundtrip() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+
+        let scalar = BigUint::parse_bytes(
+            b"483ADB7726A3C4655DA4FBFC0E1208A8F017B448A68554199C47D08FFB10E4B9",
+            16,
+        )
+        .expect("could not convert hex to scalar");
+
+        let priv_key = ecdsa
+            .priv_key_from_scalar(&scalar)
+            .expect("scalar is in range");
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+        let pub_key = ecdsa.generate_pub_key(&priv_key);
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let verify_result = ecdsa
+            .verify(&hash, &pub_key, &signature, false)
+            .expect("verify should not error");
+        assert!(verify_result, "Verification should succeed");
+    }
+
+    #[test]
+    fn test_priv_key_from_scalar_rejects_out_of_range() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+
+        assert!(matches!(
+            ecdsa.priv_key_from_scalar(&BigUint::from(0u32)),
+            Err(ECDSAErrors::PrivateKeyOutOfRange)
+        ));
+        assert!(matches!(
+            ecdsa.priv_key_from_scalar(&ecdsa.q_order),
+            Err(ECDSAErrors::PrivateKeyOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn test_sign_rejects_hash_out_of_range() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+        let k_random = ecdsa.generate_random_positive_number_less_than(&ecdsa.q_order);
+
+        let result = ecdsa.sign(&ecdsa.q_order, &priv_key, &k_random);
+
+        assert_eq!(result, Err(ECDSAErrors::HashTooLarge));
+    }
+
+    #[test]
+    fn test_verify_rejects_identity_pub_key() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let result = ecdsa.verify(&hash, &Point::Identity, &signature, false);
+
+        assert_eq!(result, Err(ECDSAErrors::PointIsIdentity));
+    }
+
+    #[test]
+    fn test_verify_rejects_pub_key_not_on_curve() {
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+
+        let message = "Bob -> 1 BTC -> Alice";
+        let hash = ecdsa.generate_hash_less_than(message, &ecdsa.q_order);
+        let signature = ecdsa
+            .sign_deterministic(&hash, &priv_key)
+            .expect("signing should succeed");
+
+        let off_curve = Point::Coor(BigUint::from(1u32), BigUint::from(1u32));
+        let result = ecdsa.verify(&hash, &off_curve, &signature, false);
+
+        assert_eq!(result, Err(ECDSAErrors::PublicKeyNotOnCurve));
+    }
 }
@@ -0,0 +1,212 @@
+use crate::ecdsa::{ECDSAErrors, StandardCurve, ECDSA};
+use crate::secret_key::SecretKey;
+use ec_generic::{FiniteField, Point};
+use num_bigint::BigUint;
+use sha256::digest;
+
+/// A proof is `(Gamma, c, s)`, following the same raw-tuple convention `ECDSA` uses for
+/// signatures rather than a dedicated struct.
+pub type VrfProof = (Point, BigUint, BigUint);
+
+/// Elliptic Curve VRF built on top of the curve operations `ECDSA` already exposes: a
+/// prover holding `priv_key` can produce a value that looks random but that anyone holding
+/// `pub_key` can verify was derived correctly from `alpha`, without learning `priv_key`.
+pub struct ECVRF {
+    ecdsa: ECDSA,
+}
+
+impl ECVRF {
+    pub fn from_standard_curve(curve: StandardCurve) -> ECVRF {
+        ECVRF {
+            ecdsa: ECDSA::from_standard_curve(curve),
+        }
+    }
+
+    ///
+    /// Gamma = priv_key * H
+    /// c = hash(H || Gamma || k*generator || k*H) mod q
+    /// s = k + c*priv_key mod q
+    ///
+    pub fn vrf_prove(&self, priv_key: &SecretKey, alpha: &[u8]) -> VrfProof {
+        // `BigUint` can't be scrubbed through its public API (see `SecretKey`'s doc comment),
+        // so the exposed scalar is held for the shortest possible scope and nothing more.
+        let priv_key = priv_key.expose_scalar();
+        let h = self.hash_to_curve(alpha);
+        let gamma = self.ecdsa.curve().scalar_mul(&h, &priv_key);
+
+        let nonce_seed = self
+            .hash_points_to_scalar(&[&h])
+            .expect("hash_to_curve never produces the identity point");
+        let k = self.ecdsa.generate_deterministic_k(&nonce_seed, &priv_key);
+
+        let k_gen = self.ecdsa.curve().scalar_mul(self.ecdsa.generator(), &k);
+        let k_h = self.ecdsa.curve().scalar_mul(&h, &k);
+        let c = self
+            .hash_points_to_scalar(&[&h, &gamma, &k_gen, &k_h])
+            .expect("gamma, k_gen and k_h are derived from a nonzero scalar and cannot be the identity point");
+
+        let s = FiniteField::add(
+            &FiniteField::mult(&c, &priv_key, self.ecdsa.order()),
+            &k,
+            self.ecdsa.order(),
+        );
+
+        (gamma, c, s)
+    }
+
+    ///
+    /// U = s*generator - c*pub_key
+    /// V = s*H - c*Gamma
+    /// valid if c == hash(H || Gamma || U || V); output is hash(Gamma)
+    ///
+    pub fn vrf_verify(
+        &self,
+        pub_key: &Point,
+        alpha: &[u8],
+        proof: &VrfProof,
+    ) -> Result<Vec<u8>, ECDSAErrors> {
+        self.ecdsa.validate_pub_key(pub_key)?;
+
+        let (gamma, c, s) = proof;
+        self.ecdsa.validate_pub_key(gamma)?;
+
+        let h = self.hash_to_curve(alpha);
+        let neg_c = self.negate_scalar(c);
+
+        let s_gen = self.ecdsa.curve().scalar_mul(self.ecdsa.generator(), s);
+        let neg_c_pub = self.ecdsa.curve().scalar_mul(pub_key, &neg_c);
+        let u = self.ecdsa.curve().add(&s_gen, &neg_c_pub);
+
+        let s_h = self.ecdsa.curve().scalar_mul(&h, s);
+        let neg_c_gamma = self.ecdsa.curve().scalar_mul(gamma, &neg_c);
+        let v = self.ecdsa.curve().add(&s_h, &neg_c_gamma);
+
+        let c_check = self.hash_points_to_scalar(&[&h, gamma, &u, &v])?;
+        if c_check != *c {
+            return Err(ECDSAErrors::InvalidVrfProof);
+        }
+
+        let output = digest(self.ecdsa.serialize_pub_key_uncompressed(gamma)?);
+        Ok(hex::decode(&output).expect("sha256 digest should be valid hex"))
+    }
+
+    /// Try-and-increment hash-to-curve: hash `alpha || ctr`, interpret the digest as an
+    /// x-coordinate, and attempt (even-parity) decompression, incrementing `ctr` until a
+    /// point on the curve is found.
+    fn hash_to_curve(&self, alpha: &[u8]) -> Point {
+        let mut ctr: u32 = 0;
+        loop {
+            let mut preimage = Vec::with_capacity(alpha.len() + 4);
+            preimage.extend_from_slice(alpha);
+            preimage.extend_from_slice(&ctr.to_be_bytes());
+
+            let hash_bytes =
+                hex::decode(digest(preimage)).expect("sha256 digest should be valid hex");
+            let x = BigUint::from_bytes_be(&hash_bytes) % &self.ecdsa.curve().p;
+
+            if let Ok(y) = self.ecdsa.decompress_y(&x, false) {
+                if let Ok(point) = self.ecdsa.checked_point(x, y) {
+                    return point;
+                }
+            }
+
+            ctr += 1;
+        }
+    }
+
+    /// Hashes the SEC1 uncompressed encoding of each point, in order, and reduces the
+    /// digest mod `q_order` to obtain a scalar challenge.
+    fn hash_points_to_scalar(&self, points: &[&Point]) -> Result<BigUint, ECDSAErrors> {
+        let mut preimage = Vec::new();
+        for point in points {
+            preimage.extend(self.ecdsa.serialize_pub_key_uncompressed(point)?);
+        }
+
+        let hash_bytes = hex::decode(digest(preimage)).expect("sha256 digest should be valid hex");
+        Ok(BigUint::from_bytes_be(&hash_bytes) % self.ecdsa.order())
+    }
+
+    fn negate_scalar(&self, x: &BigUint) -> BigUint {
+        if *x == BigUint::from(0u32) {
+            BigUint::from(0u32)
+        } else {
+            self.ecdsa.order() - x
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vrf_prove_verify_roundtrip() {
+        let vrf = ECVRF::from_standard_curve(StandardCurve::Secp256k1);
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let alpha = b"Bob -> 1 BTC -> Alice";
+        let proof = vrf.vrf_prove(&priv_key, alpha);
+
+        let output = vrf
+            .vrf_verify(&pub_key, alpha, &proof)
+            .expect("proof should verify");
+
+        assert_eq!(output.len(), 32);
+    }
+
+    #[test]
+    fn test_vrf_prove_is_deterministic() {
+        let vrf = ECVRF::from_standard_curve(StandardCurve::Secp256k1);
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+
+        let alpha = b"Bob -> 1 BTC -> Alice";
+        let proof_1 = vrf.vrf_prove(&priv_key, alpha);
+        let proof_2 = vrf.vrf_prove(&priv_key, alpha);
+
+        assert_eq!(proof_1, proof_2);
+    }
+
+    #[test]
+    fn test_vrf_verify_rejects_tampered_alpha() {
+        let vrf = ECVRF::from_standard_curve(StandardCurve::Secp256k1);
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let proof = vrf.vrf_prove(&priv_key, b"Bob -> 1 BTC -> Alice");
+
+        let result = vrf.vrf_verify(&pub_key, b"Bob -> 2 BTC -> Alice", &proof);
+
+        assert_eq!(result, Err(ECDSAErrors::InvalidVrfProof));
+    }
+
+    #[test]
+    fn test_vrf_verify_rejects_identity_gamma() {
+        let vrf = ECVRF::from_standard_curve(StandardCurve::Secp256k1);
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, pub_key) = ecdsa.generate_key_pair();
+
+        let alpha = b"Bob -> 1 BTC -> Alice";
+        let (_gamma, c, s) = vrf.vrf_prove(&priv_key, alpha);
+        let forged_proof = (Point::Identity, c, s);
+
+        let result = vrf.vrf_verify(&pub_key, alpha, &forged_proof);
+
+        assert_eq!(result, Err(ECDSAErrors::PointIsIdentity));
+    }
+
+    #[test]
+    fn test_vrf_verify_rejects_identity_pub_key() {
+        let vrf = ECVRF::from_standard_curve(StandardCurve::Secp256k1);
+        let ecdsa = ECDSA::from_standard_curve(StandardCurve::Secp256k1);
+        let (priv_key, _pub_key) = ecdsa.generate_key_pair();
+
+        let alpha = b"Bob -> 1 BTC -> Alice";
+        let proof = vrf.vrf_prove(&priv_key, alpha);
+
+        let result = vrf.vrf_verify(&Point::Identity, alpha, &proof);
+
+        assert_eq!(result, Err(ECDSAErrors::PointIsIdentity));
+    }
+}
@@ -1,4 +1,8 @@
 mod ecdsa;
+mod ecvrf;
+mod secret_key;
 
 pub use ec_generic::{EllipticCurve, FiniteField, Point};
-pub use ecdsa::{ECDSAErrors, ECDSA};
+pub use ecdsa::{ECDSAErrors, StandardCurve, ECDSA};
+pub use ecvrf::{VrfProof, ECVRF};
+pub use secret_key::SecretKey;
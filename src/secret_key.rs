@@ -0,0 +1,58 @@
+use num_bigint::BigUint;
+use zeroize::Zeroize;
+
+/// A private key scalar that zeroes its backing bytes when dropped.
+///
+/// `BigUint`'s internal `Vec<u32>` cannot be zeroed through its public API, so `SecretKey`
+/// instead owns a fixed-width big-endian byte buffer and only reconstructs a transient
+/// `BigUint` inside the crate's routines that use the key (signing, public-key derivation
+/// and VRF proving). It deliberately does not derive `Debug` or
+/// `Clone`, so accidentally logging or duplicating a key fails to compile rather than
+/// leaking key material.
+pub struct SecretKey {
+    bytes: Vec<u8>,
+}
+
+impl SecretKey {
+    pub(crate) fn from_scalar(scalar: &BigUint, byte_len: usize) -> SecretKey {
+        let mut bytes = scalar.to_bytes_be();
+        if bytes.len() < byte_len {
+            let mut padded = vec![0u8; byte_len - bytes.len()];
+            padded.extend_from_slice(&bytes);
+            bytes = padded;
+        }
+        SecretKey { bytes }
+    }
+
+    /// Reconstructs the scalar for use inside a signing routine. The caller is responsible
+    /// for letting the result drop as soon as the computation using it is done.
+    pub(crate) fn expose_scalar(&self) -> BigUint {
+        BigUint::from_bytes_be(&self.bytes)
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_scalar_roundtrips_through_expose_scalar() {
+        let scalar = BigUint::from(424242u32);
+        let secret_key = SecretKey::from_scalar(&scalar, 8);
+
+        assert_eq!(secret_key.expose_scalar(), scalar);
+    }
+
+    #[test]
+    fn test_from_scalar_pads_to_requested_byte_length() {
+        let secret_key = SecretKey::from_scalar(&BigUint::from(7u32), 4);
+
+        assert_eq!(secret_key.bytes, vec![0, 0, 0, 7]);
+    }
+}